@@ -0,0 +1,162 @@
+//! Authenticated encryption for chat payloads.
+//!
+//! Messages are sealed with XChaCha20-Poly1305 using a key derived via
+//! Argon2id from the key stored for the contact in [`crate::vault`]. Each ciphertext blob is
+//! self-describing: `salt (16 bytes) || nonce (24 bytes) || ciphertext+tag`,
+//! base64-encoded, so decryption never needs out-of-band parameters.
+
+use crate::events;
+use crate::tray::{self, TrayState};
+use crate::vault::{self, VaultState};
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    XChaCha20Poly1305, XNonce,
+};
+use rand::RngCore;
+use serde::Serialize;
+use tauri_plugin_notification::NotificationExt;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+fn derive_key(key: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut out = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(key.as_bytes(), salt, &mut out)
+        .map_err(|e| format!("key derivation failed: {e}"))?;
+    Ok(out)
+}
+
+fn seal(message: &str, key: &str) -> Result<String, String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+
+    let key_bytes = derive_key(key, &salt)?;
+    let cipher = XChaCha20Poly1305::new(&key_bytes.into());
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+    let ciphertext = cipher
+        .encrypt(&nonce, message.as_bytes())
+        .map_err(|e| format!("encryption failed: {e}"))?;
+
+    let mut blob = Vec::with_capacity(SALT_LEN + NONCE_LEN + ciphertext.len());
+    blob.extend_from_slice(&salt);
+    blob.extend_from_slice(&nonce);
+    blob.extend_from_slice(&ciphertext);
+
+    Ok(STANDARD.encode(blob))
+}
+
+fn open(ciphertext: &str, key: &str) -> Result<String, String> {
+    let blob = STANDARD
+        .decode(ciphertext)
+        .map_err(|e| format!("invalid ciphertext encoding: {e}"))?;
+
+    if blob.len() < SALT_LEN + NONCE_LEN {
+        return Err("ciphertext too short".into());
+    }
+
+    let (salt, rest) = blob.split_at(SALT_LEN);
+    let (nonce_bytes, sealed) = rest.split_at(NONCE_LEN);
+
+    let key_bytes = derive_key(key, salt)?;
+    let cipher = XChaCha20Poly1305::new(&key_bytes.into());
+    let nonce = XNonce::from_slice(nonce_bytes);
+
+    let plaintext = cipher
+        .decrypt(nonce, sealed)
+        .map_err(|_| "authentication failed: wrong key or tampered ciphertext".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|e| format!("decrypted payload is not valid utf-8: {e}"))
+}
+
+#[derive(Serialize)]
+struct DecryptedMessagePayload<'a> {
+    message: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_seal_and_open() {
+        let ciphertext = seal("hello liberty", "correct horse battery staple").unwrap();
+        let plaintext = open(&ciphertext, "correct horse battery staple").unwrap();
+        assert_eq!(plaintext, "hello liberty");
+    }
+
+    #[test]
+    fn rejects_tampered_ciphertext() {
+        let mut blob = STANDARD
+            .decode(seal("hello liberty", "correct horse battery staple").unwrap())
+            .unwrap();
+        let last = blob.len() - 1;
+        blob[last] ^= 0xff;
+        let tampered = STANDARD.encode(blob);
+
+        assert!(open(&tampered, "correct horse battery staple").is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_key() {
+        let ciphertext = seal("hello liberty", "correct horse battery staple").unwrap();
+        assert!(open(&ciphertext, "wrong passphrase").is_err());
+    }
+}
+
+#[tauri::command]
+pub async fn encrypt_message(
+    message: String,
+    contact: String,
+    vault_state: tauri::State<'_, VaultState>,
+) -> Result<String, String> {
+    let key = vault::resolve_key(&contact, &vault_state)?;
+    seal(&message, &key)
+}
+
+/// Decrypts `ciphertext` using the key stored for `contact`. When `notify` is
+/// set (a live message arrival, as opposed to e.g. redrawing chat history or
+/// re-decrypting a stored conversation), it also bumps the tray unread badge
+/// and fires a native notification; when `broadcast` is set, it fans the
+/// plaintext out as a `"message-received"` event to every open chat window.
+#[tauri::command]
+pub async fn decrypt_message(
+    ciphertext: String,
+    contact: String,
+    notify: bool,
+    broadcast: bool,
+    app: tauri::AppHandle,
+    tray_state: tauri::State<'_, TrayState>,
+    vault_state: tauri::State<'_, VaultState>,
+) -> Result<String, String> {
+    let key = vault::resolve_key(&contact, &vault_state)?;
+    let message = open(&ciphertext, &key)?;
+
+    // The decryption already succeeded at this point, so a tray/notification/
+    // broadcast failure is a best-effort side-effect, not a reason to hand
+    // the plaintext back to the caller as an error.
+    if notify {
+        if let Err(e) = tray::bump_unread(&app, &tray_state) {
+            eprintln!("failed to update tray: {e}");
+        }
+
+        if let Err(e) = app.notification().builder().title("New message").body(&message).show() {
+            eprintln!("failed to show notification: {e}");
+        }
+    }
+
+    if broadcast {
+        if let Err(e) = events::emit_to_chat_windows(
+            &app,
+            "message-received",
+            DecryptedMessagePayload { message: &message },
+        ) {
+            eprintln!("failed to broadcast message: {e}");
+        }
+    }
+
+    Ok(message)
+}
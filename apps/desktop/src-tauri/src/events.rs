@@ -0,0 +1,41 @@
+//! Multi-window event dispatch.
+//!
+//! Once Liberty Reach supports several chat windows at once, a single
+//! incoming message may need to reach more than one of them. [`emit_filter`]
+//! serializes the payload exactly once and reuses it for every matching
+//! window, instead of letting each `emit` call redo the JSON conversion.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, EventTarget};
+
+/// Emits `event` with `payload` to every webview window whose label matches
+/// `filter`, serializing `payload` only once regardless of how many windows
+/// match.
+pub fn emit_filter<F>(
+    app: &AppHandle,
+    event: &str,
+    payload: impl Serialize,
+    filter: F,
+) -> tauri::Result<()>
+where
+    F: Fn(&str) -> bool,
+{
+    let payload = serde_json::to_value(payload).map_err(tauri::Error::Json)?;
+
+    app.emit_filter(event, payload, |target| match target {
+        EventTarget::AnyLabel { label }
+        | EventTarget::Window { label }
+        | EventTarget::Webview { label }
+        | EventTarget::WebviewWindow { label } => filter(label),
+        _ => false,
+    })
+}
+
+/// Emits `event` to every chat window (label prefixed with `"chat-"`).
+pub fn emit_to_chat_windows(
+    app: &AppHandle,
+    event: &str,
+    payload: impl Serialize,
+) -> tauri::Result<()> {
+    emit_filter(app, event, payload, |label| label.starts_with("chat-"))
+}
@@ -1,24 +1,24 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod crypto;
+mod events;
+mod proxy;
+mod transport;
+mod tray;
+mod vault;
+
+use crypto::{decrypt_message, encrypt_message};
+use proxy::{get_proxy_status, set_proxy, ProxyState};
 use tauri::Manager;
+use transport::{fetch_messages, send_message};
+use tray::{set_unread_count, TrayState};
+use vault::{has_key, store_key, VaultState};
 
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! Welcome to Liberty Reach!", name)
 }
 
-#[tauri::command]
-async fn encrypt_message(message: String, key: String) -> Result<String, String> {
-    // In production, use actual crypto implementation
-    Ok(format!("encrypted:{}", message))
-}
-
-#[tauri::command]
-async fn decrypt_message(ciphertext: String, key: String) -> Result<String, String> {
-    // In production, use actual crypto implementation
-    Ok(ciphertext.replace("encrypted:", ""))
-}
-
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
@@ -29,10 +29,20 @@ fn main() {
             tauri_plugin_autostart::MacosLauncher::LaunchAgent,
             Some(vec!["--autostart"]),
         ))
+        .manage(ProxyState::default())
+        .manage(TrayState::default())
+        .manage(VaultState::default())
         .invoke_handler(tauri::generate_handler![
             greet,
             encrypt_message,
-            decrypt_message
+            decrypt_message,
+            set_proxy,
+            get_proxy_status,
+            send_message,
+            fetch_messages,
+            set_unread_count,
+            store_key,
+            has_key
         ])
         .setup(|app| {
             // Setup system tray
@@ -40,14 +50,15 @@ fn main() {
             {
                 use tauri::{
                     menu::{Menu, MenuItem},
-                    tray::{TrayIconBuilder, TrayIconEvent},
+                    tray::TrayIconBuilder,
                 };
 
                 let show_i = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
+                let mark_read_i = tray::mark_all_read_menu_item(app)?;
                 let quit_i = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
-                let menu = Menu::with_items(app, &[&show_i, &quit_i])?;
+                let menu = Menu::with_items(app, &[&show_i, &mark_read_i, &quit_i])?;
 
-                let _tray = TrayIconBuilder::new()
+                let tray_icon = TrayIconBuilder::new()
                     .icon(app.default_window_icon().unwrap().clone())
                     .menu(&menu)
                     .on_menu_event(|app, event| match event.id.as_ref() {
@@ -57,12 +68,21 @@ fn main() {
                                 let _ = window.set_focus();
                             }
                         }
+                        "mark-all-read" => {
+                            let state = app.state::<TrayState>();
+                            let _ = tray::mark_all_read(app, &state);
+                        }
                         "quit" => {
                             std::process::exit(0);
                         }
                         _ => {}
                     })
                     .build(app)?;
+
+                *app.state::<TrayState>()
+                    .tray
+                    .lock()
+                    .map_err(|_| std::io::Error::other("tray state poisoned"))? = Some(tray_icon);
             }
 
             Ok(())
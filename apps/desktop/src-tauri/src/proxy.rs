@@ -0,0 +1,57 @@
+//! Outbound transport proxying, so message traffic can be routed through a
+//! SOCKS5 proxy such as a local Tor daemon (`127.0.0.1:9050`).
+
+use std::sync::Mutex;
+
+/// Parsed proxy configuration shared across commands.
+#[derive(Default)]
+pub struct ProxyConfig {
+    /// Explicit proxy URL set via [`set_proxy`], e.g. `socks5://127.0.0.1:9050`.
+    url: Option<String>,
+}
+
+/// Tauri-managed state wrapping [`ProxyConfig`].
+#[derive(Default)]
+pub struct ProxyState(pub Mutex<ProxyConfig>);
+
+/// Resolves the effective proxy URL: the explicit override if set, otherwise
+/// the standard `ALL_PROXY` / `SOCKS_PROXY` environment variables.
+fn resolve_proxy_url(explicit: &Option<String>) -> Option<String> {
+    explicit.clone().or_else(|| {
+        std::env::var("ALL_PROXY")
+            .or_else(|_| std::env::var("SOCKS_PROXY"))
+            .ok()
+    })
+}
+
+/// Builds the shared HTTP client used for all outbound message transport,
+/// honoring whatever proxy is currently configured.
+pub fn build_client(state: &ProxyState) -> Result<reqwest::Client, String> {
+    let config = state.0.lock().map_err(|_| "proxy state poisoned".to_string())?;
+    let mut builder = reqwest::Client::builder();
+
+    if let Some(url) = resolve_proxy_url(&config.url) {
+        let proxy = reqwest::Proxy::all(&url).map_err(|e| format!("invalid proxy url: {e}"))?;
+        builder = builder.proxy(proxy);
+    }
+
+    builder
+        .build()
+        .map_err(|e| format!("failed to build http client: {e}"))
+}
+
+#[tauri::command]
+pub fn set_proxy(url: String, state: tauri::State<ProxyState>) -> Result<(), String> {
+    if !url.trim().is_empty() {
+        reqwest::Proxy::all(&url).map_err(|e| format!("invalid proxy url: {e}"))?;
+    }
+    let mut config = state.0.lock().map_err(|_| "proxy state poisoned".to_string())?;
+    config.url = if url.trim().is_empty() { None } else { Some(url) };
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_proxy_status(state: tauri::State<ProxyState>) -> Result<bool, String> {
+    let config = state.0.lock().map_err(|_| "proxy state poisoned".to_string())?;
+    Ok(resolve_proxy_url(&config.url).is_some())
+}
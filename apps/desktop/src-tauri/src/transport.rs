@@ -0,0 +1,41 @@
+//! Outbound message transport. This is the one place in the app that makes
+//! network requests, so it's where the proxy configuration in [`crate::proxy`]
+//! actually has to take effect.
+
+use crate::proxy::{build_client, ProxyState};
+
+#[tauri::command]
+pub async fn send_message(
+    url: String,
+    body: String,
+    state: tauri::State<'_, ProxyState>,
+) -> Result<(), String> {
+    let client = build_client(&state)?;
+    client
+        .post(&url)
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("failed to send message: {e}"))?
+        .error_for_status()
+        .map_err(|e| format!("send rejected by server: {e}"))?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn fetch_messages(
+    url: String,
+    state: tauri::State<'_, ProxyState>,
+) -> Result<String, String> {
+    let client = build_client(&state)?;
+    client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("failed to fetch messages: {e}"))?
+        .error_for_status()
+        .map_err(|e| format!("fetch rejected by server: {e}"))?
+        .text()
+        .await
+        .map_err(|e| format!("failed to read response body: {e}"))
+}
@@ -0,0 +1,158 @@
+//! Live tray status: an unread-count badge composited onto the base icon,
+//! plus the tooltip and "Mark all read" menu entry that go with it.
+
+use std::sync::Mutex;
+
+use image::{Rgba, RgbaImage};
+use tauri::{
+    image::Image,
+    menu::MenuItem,
+    tray::TrayIcon,
+    AppHandle, Manager,
+};
+
+/// 3x5 bitmap glyphs for the digits shown in the badge. `true` = lit pixel.
+const DIGIT_GLYPHS: [[[bool; 3]; 5]; 10] = [
+    [[true, true, true], [true, false, true], [true, false, true], [true, false, true], [true, true, true]], // 0
+    [[false, true, false], [true, true, false], [false, true, false], [false, true, false], [true, true, true]], // 1
+    [[true, true, true], [false, false, true], [true, true, true], [true, false, false], [true, true, true]], // 2
+    [[true, true, true], [false, false, true], [false, true, true], [false, false, true], [true, true, true]], // 3
+    [[true, false, true], [true, false, true], [true, true, true], [false, false, true], [false, false, true]], // 4
+    [[true, true, true], [true, false, false], [true, true, true], [false, false, true], [true, true, true]], // 5
+    [[true, true, true], [true, false, false], [true, true, true], [true, false, true], [true, true, true]], // 6
+    [[true, true, true], [false, false, true], [false, true, false], [false, true, false], [false, true, false]], // 7
+    [[true, true, true], [true, false, true], [true, true, true], [true, false, true], [true, true, true]], // 8
+    [[true, true, true], [true, false, true], [true, true, true], [false, false, true], [true, true, true]], // 9
+];
+
+/// Tray state shared across commands: the live `TrayIcon` handle (set once
+/// the tray is built in `setup`) and the current unread count.
+#[derive(Default)]
+pub struct TrayState {
+    pub tray: Mutex<Option<TrayIcon>>,
+    unread: Mutex<u32>,
+}
+
+/// Draws a digit badge (capped at "9") in the bottom-right corner of `base`.
+fn composite_badge(base: &Image<'_>, count: u32) -> Image<'static> {
+    let mut canvas = RgbaImage::from_raw(base.width(), base.height(), base.rgba().to_vec())
+        .expect("tray icon has unexpected pixel layout");
+
+    let badge_color = Rgba([220, 38, 38, 255]);
+    let digit = char::from_digit(count.min(9), 10).unwrap_or('9');
+    let glyph = DIGIT_GLYPHS[digit.to_digit(10).unwrap() as usize];
+
+    let badge_size = (canvas.width().min(canvas.height()) / 2).max(8);
+    let ox = canvas.width().saturating_sub(badge_size);
+    let oy = canvas.height().saturating_sub(badge_size);
+
+    // Filled circle behind the digit.
+    let radius = badge_size as f32 / 2.0;
+    let center = (ox as f32 + radius, oy as f32 + radius);
+    for y in oy..canvas.height() {
+        for x in ox..canvas.width() {
+            let dx = x as f32 - center.0;
+            let dy = y as f32 - center.1;
+            if dx * dx + dy * dy <= radius * radius {
+                canvas.put_pixel(x, y, badge_color);
+            }
+        }
+    }
+
+    // Digit glyph, scaled to fill most of the badge.
+    let scale = (badge_size / 6).max(1);
+    let glyph_w = 3 * scale;
+    let glyph_h = 5 * scale;
+    let gx = ox + (badge_size.saturating_sub(glyph_w)) / 2;
+    let gy = oy + (badge_size.saturating_sub(glyph_h)) / 2;
+    for (row, bits) in glyph.iter().enumerate() {
+        for (col, lit) in bits.iter().enumerate() {
+            if !lit {
+                continue;
+            }
+            for sy in 0..scale {
+                for sx in 0..scale {
+                    let x = gx + col as u32 * scale + sx;
+                    let y = gy + row as u32 * scale + sy;
+                    if x < canvas.width() && y < canvas.height() {
+                        canvas.put_pixel(x, y, Rgba([255, 255, 255, 255]));
+                    }
+                }
+            }
+        }
+    }
+
+    Image::new_owned(canvas.into_raw(), base.width(), base.height())
+}
+
+/// Refreshes the tray icon and tooltip to reflect the current unread count.
+fn refresh(app: &AppHandle, state: &TrayState) -> tauri::Result<()> {
+    let count = *state
+        .unread
+        .lock()
+        .map_err(|_| std::io::Error::other("tray state poisoned"))?;
+    let guard = state
+        .tray
+        .lock()
+        .map_err(|_| std::io::Error::other("tray state poisoned"))?;
+    let Some(tray) = guard.as_ref() else {
+        return Ok(());
+    };
+
+    let base = app
+        .default_window_icon()
+        .expect("app bundle is missing a default window icon")
+        .clone();
+
+    let icon = if count > 0 {
+        composite_badge(&base, count)
+    } else {
+        base
+    };
+    tray.set_icon(Some(icon))?;
+
+    let tooltip = if count > 0 {
+        format!("Liberty Reach — {count} unread")
+    } else {
+        "Liberty Reach".to_string()
+    };
+    tray.set_tooltip(Some(tooltip))?;
+
+    Ok(())
+}
+
+/// Resets the unread count to zero and refreshes the tray.
+pub fn mark_all_read(app: &AppHandle, state: &TrayState) -> tauri::Result<()> {
+    *state
+        .unread
+        .lock()
+        .map_err(|_| std::io::Error::other("tray state poisoned"))? = 0;
+    refresh(app, state)
+}
+
+/// Bumps the unread count by one, e.g. when a new message arrives.
+pub fn bump_unread(app: &AppHandle, state: &TrayState) -> tauri::Result<()> {
+    *state
+        .unread
+        .lock()
+        .map_err(|_| std::io::Error::other("tray state poisoned"))? += 1;
+    refresh(app, state)
+}
+
+#[tauri::command]
+pub fn set_unread_count(
+    count: u32,
+    app: AppHandle,
+    state: tauri::State<TrayState>,
+) -> Result<(), String> {
+    *state
+        .unread
+        .lock()
+        .map_err(|_| "tray state poisoned".to_string())? = count;
+    refresh(&app, &state).map_err(|e| format!("failed to update tray: {e}"))
+}
+
+/// Builds the "Mark all read" menu item for the tray menu.
+pub fn mark_all_read_menu_item(app: &AppHandle) -> tauri::Result<MenuItem<tauri::Wry>> {
+    MenuItem::with_id(app, "mark-all-read", "Mark all read", true, None::<&str>)
+}
@@ -0,0 +1,135 @@
+//! Key vault: derived keys never round-trip through the webview after this
+//! point. Keys are stored in the platform secret store, keyed by contact
+//! identifier, with an in-memory cache so the crypto commands don't hit the
+//! keychain on every message.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A key held for a contact. Wrapped rather than a bare `String` so it can't
+/// be mixed up with other string-typed command arguments.
+pub struct SecretKey(String);
+
+/// In-memory cache of keys already fetched from the platform secret store
+/// this session, keyed by contact identifier.
+#[derive(Default)]
+pub struct VaultState(Mutex<HashMap<String, SecretKey>>);
+
+#[cfg(target_os = "linux")]
+mod backend {
+    use secret_service::blocking::SecretService;
+    use secret_service::EncryptionType;
+    use std::collections::HashMap;
+
+    const SERVICE_ATTR: &str = "liberty-reach-contact";
+
+    pub fn store(contact: &str, key: &str) -> Result<(), String> {
+        let service = SecretService::connect(EncryptionType::Dh).map_err(|e| e.to_string())?;
+        let collection = service.get_default_collection().map_err(|e| e.to_string())?;
+        let attrs: HashMap<&str, &str> = HashMap::from([(SERVICE_ATTR, contact)]);
+        collection
+            .create_item(
+                &format!("Liberty Reach key for {contact}"),
+                attrs,
+                key.as_bytes(),
+                true,
+                "text/plain",
+            )
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    pub fn load(contact: &str) -> Result<Option<String>, String> {
+        let service = SecretService::connect(EncryptionType::Dh).map_err(|e| e.to_string())?;
+        let collection = service.get_default_collection().map_err(|e| e.to_string())?;
+        let attrs: HashMap<&str, &str> = HashMap::from([(SERVICE_ATTR, contact)]);
+        let items = collection.search_items(attrs).map_err(|e| e.to_string())?;
+        let Some(item) = items.first() else {
+            return Ok(None);
+        };
+        let secret = item.get_secret().map_err(|e| e.to_string())?;
+        String::from_utf8(secret)
+            .map(Some)
+            .map_err(|e| format!("stored key is not valid utf-8: {e}"))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod backend {
+    use keyring::Entry;
+
+    const SERVICE: &str = "liberty-reach";
+
+    pub fn store(contact: &str, key: &str) -> Result<(), String> {
+        Entry::new(SERVICE, contact)
+            .and_then(|entry| entry.set_password(key))
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn load(contact: &str) -> Result<Option<String>, String> {
+        match Entry::new(SERVICE, contact).and_then(|entry| entry.get_password()) {
+            Ok(key) => Ok(Some(key)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// Resolves the key for `contact`, checking the in-memory cache before
+/// falling back to the platform secret store.
+pub fn resolve_key(contact: &str, state: &VaultState) -> Result<String, String> {
+    {
+        let cache = state.0.lock().map_err(|_| "vault state poisoned".to_string())?;
+        if let Some(cached) = cache.get(contact) {
+            return Ok(cached.0.clone());
+        }
+    }
+
+    let key = backend::load(contact)?
+        .ok_or_else(|| format!("no stored key for contact '{contact}'"))?;
+
+    state
+        .0
+        .lock()
+        .map_err(|_| "vault state poisoned".to_string())?
+        .insert(contact.to_string(), SecretKey(key.clone()));
+
+    Ok(key)
+}
+
+#[tauri::command]
+pub fn store_key(
+    contact: String,
+    key: String,
+    state: tauri::State<VaultState>,
+) -> Result<(), String> {
+    backend::store(&contact, &key)?;
+    state
+        .0
+        .lock()
+        .map_err(|_| "vault state poisoned".to_string())?
+        .insert(contact, SecretKey(key));
+    Ok(())
+}
+
+#[tauri::command]
+pub fn has_key(contact: String, state: tauri::State<VaultState>) -> Result<bool, String> {
+    {
+        let cache = state.0.lock().map_err(|_| "vault state poisoned".to_string())?;
+        if cache.contains_key(&contact) {
+            return Ok(true);
+        }
+    }
+
+    let Some(key) = backend::load(&contact)? else {
+        return Ok(false);
+    };
+
+    state
+        .0
+        .lock()
+        .map_err(|_| "vault state poisoned".to_string())?
+        .insert(contact, SecretKey(key));
+
+    Ok(true)
+}